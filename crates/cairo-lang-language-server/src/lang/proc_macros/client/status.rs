@@ -1,20 +1,212 @@
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use super::ProcMacroClient;
 
+/// Base delay before the first reconnection attempt.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(200);
+/// Upper bound on the backoff delay between reconnection attempts.
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+/// Number of consecutive failed attempts allowed before giving up and transitioning to `Crashed`.
+const MAX_ATTEMPTS: u32 = 8;
+
+/// Tracks how many times a client has failed to start in a row and when it may next be retried.
+#[derive(Debug, Default, Clone)]
+pub struct RetryState {
+    /// Number of consecutive failed start attempts so far.
+    pub attempt: u32,
+    /// Earliest time at which another attempt may be made. `None` before any attempt has failed.
+    pub next_retry_at: Option<Instant>,
+    /// The error from the most recent failed attempt, if any.
+    pub last_error: Option<Arc<str>>,
+}
+
+impl RetryState {
+    /// Returns the state to move to after a start attempt fails at `now` with `error`, applying
+    /// capped exponential backoff to the next retry.
+    fn after_failure(&self, now: Instant, error: String) -> Self {
+        let backoff = INITIAL_BACKOFF.saturating_mul(1 << self.attempt.min(16)).min(MAX_BACKOFF);
+        let attempt = self.attempt + 1;
+        Self { attempt, next_retry_at: Some(now + backoff), last_error: Some(error.into()) }
+    }
+
+    /// Whether `MAX_ATTEMPTS` consecutive failures have accumulated and the client should stop
+    /// retrying and be considered `Crashed`.
+    fn exhausted(&self) -> bool {
+        self.attempt >= MAX_ATTEMPTS
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub enum ClientStatus {
+    /// No start attempt is in flight. Carries the retry bookkeeping so a run of failures is
+    /// remembered across attempts; freshly `Default`, it has never failed.
     #[default]
-    Pending,
-    Starting(Arc<ProcMacroClient>),
+    Pending(RetryState),
+    /// A start attempt is in flight, carrying the retry bookkeeping accumulated by prior
+    /// attempts so it can be restored on `Pending` if this one fails too.
+    Starting(Arc<ProcMacroClient>, RetryState),
     Ready(Arc<ProcMacroClient>),
-    /// Failed to start multiple times.
+    /// Failed to start `MAX_ATTEMPTS` times in a row.
     /// No more actions will be taken.
     Crashed,
 }
 
 impl ClientStatus {
     pub fn is_pending(&self) -> bool {
-        matches!(self, Self::Pending)
+        matches!(self, Self::Pending(_))
     }
-}
\ No newline at end of file
+
+    /// Whether this status is due for another start attempt at `now`, i.e. it is `Pending` and
+    /// either has never failed or its backoff delay has elapsed.
+    pub fn should_retry(&self, now: Instant) -> bool {
+        match self {
+            Self::Pending(retry) => retry.next_retry_at.map_or(true, |at| now >= at),
+            Self::Starting(..) | Self::Ready(_) | Self::Crashed => false,
+        }
+    }
+
+    /// Applies a failed start attempt or handshake at `now`; [`Self::tick`] calls this for both.
+    /// Moves to `Pending` with the failure counter incremented and the next attempt scheduled after a
+    /// capped exponential backoff, or to `Crashed` once `MAX_ATTEMPTS` have been exhausted.
+    pub fn fail(&self, now: Instant, error: String) -> Self {
+        let retry = match self {
+            Self::Pending(retry) | Self::Starting(_, retry) => retry.after_failure(now, error),
+            Self::Ready(_) | Self::Crashed => RetryState::default().after_failure(now, error),
+        };
+        if retry.exhausted() { Self::Crashed } else { Self::Pending(retry) }
+    }
+
+    /// The number of consecutive failed attempts so far, for display as e.g. "proc-macro server
+    /// restarting (attempt N)". Resets to 0 once a client reaches `Ready`.
+    pub fn attempt(&self) -> u32 {
+        match self {
+            Self::Pending(retry) | Self::Starting(_, retry) => retry.attempt,
+            Self::Ready(_) | Self::Crashed => 0,
+        }
+    }
+
+    /// The error from the most recent failed attempt, if any.
+    pub fn last_error(&self) -> Option<&Arc<str>> {
+        match self {
+            Self::Pending(retry) | Self::Starting(_, retry) => retry.last_error.as_ref(),
+            Self::Ready(_) | Self::Crashed => None,
+        }
+    }
+
+    /// Single entry point for the host loop to drive reconnection on every tick, whatever phase
+    /// `self` is in:
+    /// - `Pending` due for a retry (per [`Self::should_retry`]): calls `start` and moves to
+    ///   `Starting` on success or applies [`Self::fail`] on failure.
+    /// - `Starting`: calls `poll_handshake` to check on the in-flight handshake. `Some(Ok(()))`
+    ///   promotes it to `Ready`; `Some(Err(error))` applies [`Self::fail`], moving it back to
+    ///   `Pending` (or `Crashed`) exactly like a failed start attempt; `None` (handshake still in
+    ///   flight) leaves the status unchanged.
+    /// - `Pending` not yet due, `Ready`, `Crashed`: returned unchanged.
+    ///
+    /// This is the only entry point the host loop needs: it replaces calling [`Self::fail`]
+    /// directly for a failed handshake.
+    pub fn tick(
+        &self,
+        now: Instant,
+        start: impl FnOnce() -> Result<Arc<ProcMacroClient>, String>,
+        poll_handshake: impl FnOnce(&Arc<ProcMacroClient>) -> Option<Result<(), String>>,
+    ) -> Self {
+        match self {
+            Self::Pending(retry) => {
+                if !self.should_retry(now) {
+                    return self.clone();
+                }
+                match start() {
+                    Ok(client) => Self::Starting(client, retry.clone()),
+                    Err(error) => self.fail(now, error),
+                }
+            }
+            Self::Starting(client, _) => match poll_handshake(client) {
+                Some(Ok(())) => Self::Ready(client.clone()),
+                Some(Err(error)) => self.fail(now, error),
+                None => self.clone(),
+            },
+            Self::Ready(_) | Self::Crashed => self.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_failure_backs_off_by_the_base_delay() {
+        let now = Instant::now();
+        let status = ClientStatus::default().fail(now, "boom".to_string());
+        let ClientStatus::Pending(retry) = &status else {
+            panic!("expected Pending after a single failure, got {status:?}");
+        };
+        assert_eq!(retry.attempt, 1);
+        assert_eq!(retry.next_retry_at, Some(now + INITIAL_BACKOFF));
+    }
+
+    #[test]
+    fn backoff_doubles_with_each_consecutive_failure() {
+        let now = Instant::now();
+        let mut status = ClientStatus::default();
+        let mut expected = INITIAL_BACKOFF;
+        for _ in 0..3 {
+            status = status.fail(now, "boom".to_string());
+            let ClientStatus::Pending(retry) = &status else {
+                panic!("expected Pending, got {status:?}");
+            };
+            assert_eq!(retry.next_retry_at, Some(now + expected));
+            expected *= 2;
+        }
+    }
+
+    #[test]
+    fn crashes_after_max_attempts_consecutive_failures() {
+        let now = Instant::now();
+        let mut status = ClientStatus::default();
+        for _ in 0..MAX_ATTEMPTS {
+            status = status.fail(now, "boom".to_string());
+        }
+        assert!(matches!(status, ClientStatus::Crashed));
+    }
+
+    #[test]
+    fn should_retry_waits_for_the_backoff_to_elapse() {
+        let now = Instant::now();
+        let status = ClientStatus::default().fail(now, "boom".to_string());
+        assert!(!status.should_retry(now));
+        assert!(status.should_retry(now + INITIAL_BACKOFF));
+    }
+
+    #[test]
+    fn tick_is_a_no_op_before_the_backoff_elapses() {
+        let now = Instant::now();
+        let status = ClientStatus::default().fail(now, "boom".to_string());
+        let ticked = status.tick(
+            now,
+            || panic!("must not attempt to start before backoff elapses"),
+            |_| panic!("not Starting, must not poll a handshake"),
+        );
+        assert_eq!(ticked.attempt(), status.attempt());
+    }
+
+    #[test]
+    fn tick_retries_and_reapplies_failure_once_the_backoff_elapses() {
+        let now = Instant::now();
+        let status = ClientStatus::default().fail(now, "boom".to_string());
+        let retried_at = now + INITIAL_BACKOFF;
+        let ticked = status.tick(
+            retried_at,
+            || Err("boom again".to_string()),
+            |_| panic!("not Starting, must not poll a handshake"),
+        );
+        assert_eq!(ticked.attempt(), 2);
+    }
+
+    // `tick`'s `Starting` branch (handshake success promotes to `Ready`, failure applies `fail`,
+    // still-in-flight leaves the status unchanged) is exercised by inspection rather than a unit
+    // test here: building a `Starting(Arc<ProcMacroClient>, _)` fixture requires constructing a
+    // real `ProcMacroClient`, whose constructor lives outside this file.
+}
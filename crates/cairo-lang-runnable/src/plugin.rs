@@ -3,13 +3,16 @@ use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{
     MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::Severity;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::plugin::{AnalyzerPlugin, PluginSuite};
 use cairo_lang_semantic::{GenericArgumentId, Mutability, corelib};
 use cairo_lang_syntax::attribute::consts::IMPLICIT_PRECEDENCE_ATTR;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::{OptionWrappedGenericParamListHelper, QueryAttrs};
-use cairo_lang_syntax::node::{TypedStablePtr, ast};
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode, ast};
 use indoc::formatdoc;
 use itertools::Itertools;
 
@@ -26,6 +29,89 @@ pub fn runnable_plugin_suite() -> PluginSuite {
     )
 }
 
+/// Stable, tool-readable identifiers for the diagnostics emitted by the runnable plugins, paired
+/// with their English wording in one place so the two can't drift apart and so downstream
+/// tooling (e.g. test snapshots) can group or filter on `code()` instead of matching on text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RunnableDiagnosticCode {
+    /// `#[runnable]` function declares generic params.
+    GenericParamsNotAllowed,
+    /// `#[runnable_raw]` function has a non-`()` return type.
+    WrongReturnType,
+    /// `#[runnable_raw]` function doesn't have exactly 2 params.
+    WrongArity,
+    /// `#[runnable_raw]` function's first param isn't `Span<felt252>`.
+    WrongFirstParamType,
+    /// `#[runnable_raw]` function's first param is passed by `ref`.
+    WrongFirstParamMutability,
+    /// `#[runnable_raw]` function's second param isn't `Array<felt252>`.
+    WrongSecondParamType,
+    /// `#[runnable_raw]` function's second param isn't passed by `ref`.
+    WrongSecondParamMutability,
+    /// `#[runnable_raw]` function's signature has two or more of the above defects at once,
+    /// collapsed into a single diagnostic.
+    WrongSignature,
+}
+
+impl RunnableDiagnosticCode {
+    /// The stable machine code, e.g. `RUN0001`.
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::GenericParamsNotAllowed => "RUN0001",
+            Self::WrongReturnType => "RUN0002",
+            Self::WrongArity => "RUN0003",
+            Self::WrongFirstParamType => "RUN0004",
+            Self::WrongFirstParamMutability => "RUN0005",
+            Self::WrongSecondParamType => "RUN0006",
+            Self::WrongSecondParamMutability => "RUN0007",
+            Self::WrongSignature => "RUN0008",
+        }
+    }
+
+    /// The catalog wording for this code, with no code prefix.
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::GenericParamsNotAllowed => "Runnable functions cannot have generic params.",
+            Self::WrongReturnType => {
+                "Invalid return type for `#[runnable_raw]` function, expected `()`."
+            }
+            Self::WrongArity => {
+                "Invalid number of params for `#[runnable_raw]` function, expected 2."
+            }
+            Self::WrongFirstParamType => {
+                "Invalid first param type for `#[runnable_raw]` function, expected \
+                 `Span<felt252>`."
+            }
+            Self::WrongFirstParamMutability => {
+                "Invalid first param mutability for `#[runnable_raw]` function, got unexpected \
+                 `ref`."
+            }
+            Self::WrongSecondParamType => {
+                "Invalid second param type for `#[runnable_raw]` function, expected \
+                 `Array<felt252>`."
+            }
+            Self::WrongSecondParamMutability => {
+                "Invalid second param mutability for `#[runnable_raw]` function, expected `ref`."
+            }
+            Self::WrongSignature => {
+                "Invalid signature for `#[runnable_raw]` function, expected `fn \
+                 ...(mut input: Span<felt252>, ref output: Array<felt252>) -> ()`."
+            }
+        }
+    }
+
+    /// The human-readable text to render in a diagnostic: the stable code followed by the
+    /// catalog wording, e.g. `RUN0001: Runnable functions cannot have generic params.`. This is
+    /// for display only — `PluginDiagnostic` has no structured code field to carry `self`
+    /// alongside the rendered text, so callers that need to assert or filter on the code (tests,
+    /// `diagnostics_to_json`) should get it from the `(RunnableDiagnosticCode, PluginDiagnostic)`
+    /// pairs returned by [`RawRunnableAnalyzer::diagnostics_with_codes`] /
+    /// [`RunnablePlugin::diagnostics_with_codes`], not by parsing this string back apart.
+    pub fn format(self) -> String {
+        format!("{}: {}", self.code(), self.message())
+    }
+}
+
 const IMPLICIT_PRECEDENCE: &[&str] = &[
     "core::pedersen::Pedersen",
     "core::RangeCheck",
@@ -41,6 +127,30 @@ const IMPLICIT_PRECEDENCE: &[&str] = &[
 #[non_exhaustive]
 struct RunnablePlugin;
 
+impl RunnablePlugin {
+    /// Like [`MacroPlugin::generate_code`]'s diagnostics, but paired with the
+    /// [`RunnableDiagnosticCode`] that produced each one, so a caller (e.g. one feeding
+    /// [`diagnostics_to_json`]) can get the code structurally instead of scraping it back out of
+    /// the rendered message. `generate_code` reports these diagnostics, without their codes, and
+    /// generates no code when there are any.
+    pub fn diagnostics_with_codes(
+        db: &dyn SyntaxGroup,
+        item: &ast::FreeFunction,
+    ) -> Vec<(RunnableDiagnosticCode, PluginDiagnostic)> {
+        let generics = item.declaration(db).generic_params(db);
+        if !generics.is_empty(db) {
+            return vec![(
+                RunnableDiagnosticCode::GenericParamsNotAllowed,
+                PluginDiagnostic::error(
+                    &generics,
+                    RunnableDiagnosticCode::GenericParamsNotAllowed.format(),
+                ),
+            )];
+        }
+        vec![]
+    }
+}
+
 impl MacroPlugin for RunnablePlugin {
     fn generate_code(
         &self,
@@ -54,17 +164,13 @@ impl MacroPlugin for RunnablePlugin {
         if !item.has_attr(db, RUNNABLE_ATTR) {
             return PluginResult::default();
         }
-        let mut diagnostics = vec![];
-        let mut builder = PatchBuilder::new(db, &item);
-        let declaration = item.declaration(db);
-        let generics = declaration.generic_params(db);
-        if !generics.is_empty(db) {
-            diagnostics.push(PluginDiagnostic::error(
-                &generics,
-                "Runnable functions cannot have generic params.".to_string(),
-            ));
+        let diagnostics: Vec<PluginDiagnostic> =
+            Self::diagnostics_with_codes(db, &item).into_iter().map(|(_, d)| d).collect();
+        if !diagnostics.is_empty() {
             return PluginResult { code: None, diagnostics, remove_original_item: false };
         }
+        let mut builder = PatchBuilder::new(db, &item);
+        let declaration = item.declaration(db);
         let name = declaration.name(db);
         let implicits_precedence =
             RewriteNode::Text(format!("#[{IMPLICIT_PRECEDENCE_ATTR}({})]", {
@@ -135,12 +241,67 @@ impl MacroPlugin for RunnablePlugin {
     }
 }
 
+/// The params every `#[runnable_raw]` function must declare, used as the replacement text of the
+/// structured [`RunnableRawSuggestion`] for an arity mismatch. The checks themselves compare the
+/// resolved param types and mutabilities, not this text.
+const RUNNABLE_RAW_CANONICAL_PARAMS: &str =
+    "(mut input: Span<felt252>, ref output: Array<felt252>)";
+
+/// The modifier a param was actually declared with, for use in "found `{}`" diagnostic text.
+fn mutability_label(mutability: Mutability) -> &'static str {
+    match mutability {
+        Mutability::Immutable => "none",
+        Mutability::Mutable => "mut",
+        Mutability::Reference => "ref",
+    }
+}
+
+/// Whether `defect_count` simultaneous signature defects should be collapsed into a single
+/// consolidated [`RunnableDiagnosticCode::WrongSignature`] diagnostic rather than reported as
+/// separate, individually-focused diagnostics.
+fn should_consolidate(defect_count: usize) -> bool {
+    defect_count >= 2
+}
+
+/// A machine-applicable quick-fix for a `#[runnable_raw]` signature diagnostic: the exact span
+/// to replace and the text to replace it with, so an IDE can apply it with one click.
+///
+/// This lives in this crate rather than on `PluginDiagnostic` itself (defined in
+/// `cairo_lang_defs`) because that type has no `suggestion` field to carry it; pairing it
+/// alongside the diagnostic here, via [`RunnableRawDiagnostic`], is the closest approximation
+/// available without changing `cairo_lang_defs`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunnableRawSuggestion {
+    pub span: TextSpan,
+    pub replacement: String,
+}
+
+/// A `#[runnable_raw]` diagnostic paired with the [`RunnableDiagnosticCode`] that produced it and,
+/// when the defect is a single mechanical rewrite, a machine-applicable quick-fix.
+#[derive(Debug, Clone)]
+pub struct RunnableRawDiagnostic {
+    pub code: RunnableDiagnosticCode,
+    pub diagnostic: PluginDiagnostic,
+    pub suggestion: Option<RunnableRawSuggestion>,
+}
+
 /// Plugin to add diagnostics on bad `#[runnable_raw]` annotations.
+///
+/// When a function's signature has two or more defects at once, they're collapsed into a single
+/// diagnostic showing the full expected shape instead of one independent error per defect.
 #[derive(Default, Debug)]
 struct RawRunnableAnalyzer;
 
-impl AnalyzerPlugin for RawRunnableAnalyzer {
-    fn diagnostics(&self, db: &dyn SemanticGroup, module_id: ModuleId) -> Vec<PluginDiagnostic> {
+impl RawRunnableAnalyzer {
+    /// Like [`AnalyzerPlugin::diagnostics`], but pairs each diagnostic with a machine-applicable
+    /// [`RunnableRawSuggestion`] when the defect can be fixed by a single mechanical rewrite (the
+    /// collapsed multi-defect diagnostic, which spans more than one rewrite, has none).
+    /// `AnalyzerPlugin::diagnostics` is a thin wrapper over this that the trait requires.
+    pub fn diagnostics_with_suggestions(
+        &self,
+        db: &dyn SemanticGroup,
+        module_id: ModuleId,
+    ) -> Vec<RunnableRawDiagnostic> {
         let syntax_db = db.upcast();
         let mut diagnostics = vec![];
         let Ok(free_functions) = db.module_free_functions(module_id) else {
@@ -153,58 +314,357 @@ impl AnalyzerPlugin for RawRunnableAnalyzer {
             let Ok(signature) = db.free_function_signature(*id) else {
                 continue;
             };
-            if signature.return_type != corelib::unit_ty(db) {
-                diagnostics.push(PluginDiagnostic::error(
-                    &signature.stable_ptr.lookup(syntax_db).ret_ty(syntax_db),
-                    "Invalid return type for `#[runnable_raw]` function, expected `()`."
-                        .to_string(),
-                ));
-            }
             let [input, output] = &signature.params[..] else {
-                diagnostics.push(PluginDiagnostic::error(
-                    &signature.stable_ptr.lookup(syntax_db).parameters(syntax_db),
-                    "Invalid number of params for `#[runnable_raw]` function, expected 2."
-                        .to_string(),
-                ));
+                let parameters = signature.stable_ptr.lookup(syntax_db).parameters(syntax_db);
+                diagnostics.push(RunnableRawDiagnostic {
+                    code: RunnableDiagnosticCode::WrongArity,
+                    diagnostic: PluginDiagnostic::error(
+                        &parameters,
+                        RunnableDiagnosticCode::WrongArity.format(),
+                    ),
+                    suggestion: Some(RunnableRawSuggestion {
+                        span: parameters.as_syntax_node().span(syntax_db),
+                        replacement: RUNNABLE_RAW_CANONICAL_PARAMS.to_string(),
+                    }),
+                });
                 continue;
             };
-            if input.ty
+            let return_type_bad = signature.return_type != corelib::unit_ty(db);
+            let first_type_bad = input.ty
                 != corelib::get_core_ty_by_name(db, "Span".into(), vec![GenericArgumentId::Type(
                     corelib::core_felt252_ty(db),
-                )])
-            {
-                diagnostics.push(PluginDiagnostic::error(
-                    input.stable_ptr.untyped(),
-                    "Invalid first param type for `#[runnable_raw]` function, expected \
-                     `Span<felt252>`."
-                        .to_string(),
-                ));
+                )]);
+            let first_mutability_bad = input.mutability == Mutability::Reference;
+            let second_type_bad = output.ty != corelib::core_array_felt252_ty(db);
+            let second_mutability_bad = output.mutability != Mutability::Reference;
+            let defect_count = [
+                return_type_bad,
+                first_type_bad,
+                first_mutability_bad,
+                second_type_bad,
+                second_mutability_bad,
+            ]
+            .into_iter()
+            .filter(|bad| *bad)
+            .count();
+
+            if should_consolidate(defect_count) {
+                // Several checks failed at once: collapse them into a single diagnostic anchored
+                // on the whole signature that shows the expected shape alongside a compact
+                // enumeration of each mismatch, instead of a wall of independent errors.
+                let mut mismatches = vec![];
+                if return_type_bad {
+                    mismatches.push(format!(
+                        "  return type: found `{}`, expected `()`",
+                        signature.return_type.format(db)
+                    ));
+                }
+                if first_type_bad {
+                    mismatches.push(format!(
+                        "  param #1 type: found `{}`, expected `Span<felt252>`",
+                        input.ty.format(db)
+                    ));
+                }
+                if first_mutability_bad {
+                    mismatches.push("  param #1 mutability: found `ref`, expected none".to_string());
+                }
+                if second_type_bad {
+                    mismatches.push(format!(
+                        "  param #2 type: found `{}`, expected `Array<felt252>`",
+                        output.ty.format(db)
+                    ));
+                }
+                if second_mutability_bad {
+                    mismatches.push(format!(
+                        "  param #2 mutability: found `{}`, expected `ref`",
+                        mutability_label(output.mutability)
+                    ));
+                }
+                diagnostics.push(RunnableRawDiagnostic {
+                    code: RunnableDiagnosticCode::WrongSignature,
+                    diagnostic: PluginDiagnostic::error(
+                        &signature.stable_ptr.lookup(syntax_db),
+                        format!(
+                            "{}\n{}",
+                            RunnableDiagnosticCode::WrongSignature.format(),
+                            mismatches.join("\n")
+                        ),
+                    ),
+                    suggestion: None,
+                });
+                continue;
+            }
+
+            if return_type_bad {
+                let ret_ty = signature.stable_ptr.lookup(syntax_db).ret_ty(syntax_db);
+                diagnostics.push(RunnableRawDiagnostic {
+                    code: RunnableDiagnosticCode::WrongReturnType,
+                    diagnostic: PluginDiagnostic::error(
+                        &ret_ty,
+                        RunnableDiagnosticCode::WrongReturnType.format(),
+                    ),
+                    suggestion: Some(RunnableRawSuggestion {
+                        span: ret_ty.as_syntax_node().span(syntax_db),
+                        replacement: "-> ()".to_string(),
+                    }),
+                });
             }
-            if input.mutability == Mutability::Reference {
-                diagnostics.push(PluginDiagnostic::error(
-                    input.stable_ptr.untyped(),
-                    "Invalid first param mutability for `#[runnable_raw]` function, got \
-                     unexpected `ref`."
-                        .to_string(),
-                ));
+            if first_type_bad {
+                let param = input.stable_ptr.lookup(syntax_db);
+                diagnostics.push(RunnableRawDiagnostic {
+                    code: RunnableDiagnosticCode::WrongFirstParamType,
+                    diagnostic: PluginDiagnostic::error(
+                        input.stable_ptr.untyped(),
+                        RunnableDiagnosticCode::WrongFirstParamType.format(),
+                    ),
+                    suggestion: Some(RunnableRawSuggestion {
+                        span: param.as_syntax_node().span(syntax_db),
+                        replacement: "mut input: Span<felt252>".to_string(),
+                    }),
+                });
             }
-            if output.ty != corelib::core_array_felt252_ty(db) {
-                diagnostics.push(PluginDiagnostic::error(
-                    output.stable_ptr.untyped(),
-                    "Invalid second param type for `#[runnable_raw]` function, expected \
-                     `Array<felt252>`."
-                        .to_string(),
-                ));
+            if first_mutability_bad {
+                let param = input.stable_ptr.lookup(syntax_db);
+                diagnostics.push(RunnableRawDiagnostic {
+                    code: RunnableDiagnosticCode::WrongFirstParamMutability,
+                    diagnostic: PluginDiagnostic::error(
+                        input.stable_ptr.untyped(),
+                        RunnableDiagnosticCode::WrongFirstParamMutability.format(),
+                    ),
+                    suggestion: Some(RunnableRawSuggestion {
+                        span: param.as_syntax_node().span(syntax_db),
+                        replacement: "mut input: Span<felt252>".to_string(),
+                    }),
+                });
             }
-            if output.mutability != Mutability::Reference {
-                diagnostics.push(PluginDiagnostic::error(
-                    output.stable_ptr.untyped(),
-                    "Invalid second param mutability for `#[runnable_raw]` function, expected \
-                     `ref`."
-                        .to_string(),
-                ));
+            if second_type_bad {
+                let param = output.stable_ptr.lookup(syntax_db);
+                diagnostics.push(RunnableRawDiagnostic {
+                    code: RunnableDiagnosticCode::WrongSecondParamType,
+                    diagnostic: PluginDiagnostic::error(
+                        output.stable_ptr.untyped(),
+                        RunnableDiagnosticCode::WrongSecondParamType.format(),
+                    ),
+                    suggestion: Some(RunnableRawSuggestion {
+                        span: param.as_syntax_node().span(syntax_db),
+                        replacement: "ref output: Array<felt252>".to_string(),
+                    }),
+                });
+            }
+            if second_mutability_bad {
+                let param = output.stable_ptr.lookup(syntax_db);
+                diagnostics.push(RunnableRawDiagnostic {
+                    code: RunnableDiagnosticCode::WrongSecondParamMutability,
+                    diagnostic: PluginDiagnostic::error(
+                        output.stable_ptr.untyped(),
+                        RunnableDiagnosticCode::WrongSecondParamMutability.format(),
+                    ),
+                    suggestion: Some(RunnableRawSuggestion {
+                        span: param.as_syntax_node().span(syntax_db),
+                        replacement: "ref output: Array<felt252>".to_string(),
+                    }),
+                });
             }
         }
         diagnostics
     }
+
+    /// Like [`Self::diagnostics_with_suggestions`], but stripped down to the `(code, diagnostic)`
+    /// pairs that [`diagnostics_to_json`] needs, for callers that don't care about suggestions.
+    pub fn diagnostics_with_codes(
+        &self,
+        db: &dyn SemanticGroup,
+        module_id: ModuleId,
+    ) -> Vec<(RunnableDiagnosticCode, PluginDiagnostic)> {
+        self.diagnostics_with_suggestions(db, module_id)
+            .into_iter()
+            .map(|d| (d.code, d.diagnostic))
+            .collect()
+    }
+}
+
+impl AnalyzerPlugin for RawRunnableAnalyzer {
+    fn diagnostics(&self, db: &dyn SemanticGroup, module_id: ModuleId) -> Vec<PluginDiagnostic> {
+        self.diagnostics_with_suggestions(db, module_id).into_iter().map(|d| d.diagnostic).collect()
+    }
+}
+
+/// One entry in the JSON problem-matcher stream produced by [`diagnostics_to_json`].
+struct RunnableDiagnosticJson {
+    severity: &'static str,
+    file: String,
+    /// 1-based line number.
+    line: usize,
+    /// 1-based column number.
+    column: usize,
+    /// The stable code of the diagnostic that produced this entry, e.g. `RUN0001`.
+    code: &'static str,
+    message: String,
+}
+
+impl RunnableDiagnosticJson {
+    /// Renders this entry as a JSON object literal.
+    fn to_json(&self) -> String {
+        format!(
+            "{{\"severity\":\"{}\",\"file\":\"{}\",\"line\":{},\"column\":{},\"code\":\"{}\",\
+             \"message\":\"{}\"}}",
+            self.severity,
+            json_escape(&self.file),
+            self.line,
+            self.column,
+            self.code,
+            json_escape(&self.message)
+        )
+    }
+}
+
+/// Escapes `value` for embedding in a JSON string literal.
+fn json_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Serializes `diagnostics` into the JSON problem-matcher shape consumed by editors and CI,
+/// resolving each diagnostic's `stable_ptr` to a concrete file+line+column. Feed it the output of
+/// [`RawRunnableAnalyzer::diagnostics_with_codes`] (or the codes surfaced alongside
+/// `RunnablePlugin`'s diagnostics internally) — the `code` field is taken directly from the paired
+/// [`RunnableDiagnosticCode`], never parsed back out of the rendered message. This is opt-in:
+/// callers (e.g. `scarb`, behind a `--json` flag) call this instead of letting diagnostics render
+/// as human-readable text, so existing consumers are unaffected.
+pub fn diagnostics_to_json(
+    db: &dyn SemanticGroup,
+    diagnostics: &[(RunnableDiagnosticCode, PluginDiagnostic)],
+) -> String {
+    let syntax_db: &dyn SyntaxGroup = db.upcast();
+    let files_db: &dyn FilesGroup = db.upcast();
+    let entries: Vec<RunnableDiagnosticJson> = diagnostics
+        .iter()
+        .filter_map(|(code, diagnostic)| {
+            let file_id = diagnostic.stable_ptr.file_id(syntax_db);
+            let span = diagnostic.stable_ptr.lookup(syntax_db).span(syntax_db);
+            let position = span.start.position_in_file(files_db, file_id)?;
+            Some(RunnableDiagnosticJson {
+                severity: match diagnostic.severity {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                },
+                file: file_id.full_path(files_db),
+                line: position.line + 1,
+                column: position.col + 1,
+                code: code.code(),
+                message: diagnostic.message.clone(),
+            })
+        })
+        .collect();
+    format!("[{}]", entries.iter().map(RunnableDiagnosticJson::to_json).join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_escape_handles_quotes_backslashes_and_control_chars() {
+        assert_eq!(json_escape("plain"), "plain");
+        assert_eq!(json_escape(r#"say "hi""#), r#"say \"hi\""#);
+        assert_eq!(json_escape(r"C:\path"), r"C:\\path");
+        assert_eq!(json_escape("line1\nline2"), "line1\\nline2");
+    }
+
+    #[test]
+    fn every_diagnostic_code_has_a_distinct_stable_code() {
+        let codes = [
+            RunnableDiagnosticCode::GenericParamsNotAllowed,
+            RunnableDiagnosticCode::WrongReturnType,
+            RunnableDiagnosticCode::WrongArity,
+            RunnableDiagnosticCode::WrongFirstParamType,
+            RunnableDiagnosticCode::WrongFirstParamMutability,
+            RunnableDiagnosticCode::WrongSecondParamType,
+            RunnableDiagnosticCode::WrongSecondParamMutability,
+            RunnableDiagnosticCode::WrongSignature,
+        ];
+        let mut seen = std::collections::HashSet::new();
+        for code in codes {
+            assert!(code.code().starts_with("RUN"));
+            assert!(seen.insert(code.code()), "duplicate stable code: {}", code.code());
+            assert_eq!(code.format(), format!("{}: {}", code.code(), code.message()));
+        }
+    }
+
+    #[test]
+    fn mutability_label_matches_the_actual_declared_modifier() {
+        assert_eq!(mutability_label(Mutability::Immutable), "none");
+        assert_eq!(mutability_label(Mutability::Mutable), "mut");
+        assert_eq!(mutability_label(Mutability::Reference), "ref");
+    }
+
+    #[test]
+    fn consolidates_only_when_two_or_more_defects_fire_at_once() {
+        assert!(!should_consolidate(0));
+        assert!(!should_consolidate(1));
+        assert!(should_consolidate(2));
+        assert!(should_consolidate(5));
+    }
+
+    // The tests below drive `diagnostics_with_suggestions` against real parsed
+    // `#[runnable_raw]` functions, using the same `SemanticDatabaseForTesting` /
+    // `setup_test_module` harness the rest of the compiler's analyzer-plugin tests use, rather
+    // than asserting on `should_consolidate`/`mutability_label` in isolation.
+    use cairo_lang_semantic::test_utils::{SemanticDatabaseForTesting, setup_test_module};
+
+    #[test]
+    fn single_defect_stays_focused_and_keeps_its_suggestion() {
+        let mut db = SemanticDatabaseForTesting::default();
+        let test_module = setup_test_module(
+            &mut db,
+            indoc::indoc! {"
+                #[runnable_raw]
+                fn foo(mut input: Span<felt252>, output: Array<felt252>) {}
+            "},
+        )
+        .unwrap();
+        let diagnostics =
+            RawRunnableAnalyzer.diagnostics_with_suggestions(&db, test_module.module_id);
+        let [diagnostic] = &diagnostics[..] else {
+            panic!("expected exactly one diagnostic, got {diagnostics:?}");
+        };
+        assert_eq!(diagnostic.code, RunnableDiagnosticCode::WrongSecondParamMutability);
+        let suggestion =
+            diagnostic.suggestion.as_ref().expect("a single defect should carry a suggestion");
+        assert_eq!(suggestion.replacement, "ref output: Array<felt252>");
+    }
+
+    #[test]
+    fn multiple_defects_collapse_into_one_wrong_signature_diagnostic() {
+        let mut db = SemanticDatabaseForTesting::default();
+        let test_module = setup_test_module(
+            &mut db,
+            indoc::indoc! {"
+                #[runnable_raw]
+                fn foo(input: felt252, output: Array<felt252>) {}
+            "},
+        )
+        .unwrap();
+        let diagnostics =
+            RawRunnableAnalyzer.diagnostics_with_suggestions(&db, test_module.module_id);
+        let [diagnostic] = &diagnostics[..] else {
+            panic!("expected the defects to collapse into exactly one diagnostic, got {diagnostics:?}");
+        };
+        assert_eq!(diagnostic.code, RunnableDiagnosticCode::WrongSignature);
+        assert!(
+            diagnostic.suggestion.is_none(),
+            "a collapsed multi-defect diagnostic has no single mechanical rewrite"
+        );
+    }
 }
\ No newline at end of file